@@ -1,30 +1,94 @@
 use std::collections::BTreeMap;
 
-use heck::ToSnakeCase;
-use openapiv3::{OpenAPI, Operation, Parameter, PathItem, ReferenceOr};
+use heck::{ToPascalCase, ToSnakeCase};
+use openapiv3::{
+    AdditionalProperties, ObjectType, OpenAPI, Operation, Parameter, PathItem, ReferenceOr,
+    RequestBody, Response, Schema, SchemaKind,
+};
+use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{Expr, FnArg, Item, ReturnType, Type};
+use syn::{FnArg, Item, ReturnType, Type};
 
-struct OapiState {
-    _type_cache: BTreeMap<String, syn::Type>,
-    _objects: BTreeMap<String, Item>,
+/// Non-`form`/`explode: true` array serialization styles, which join all
+/// items into a single delimited value instead of repeating the key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum CollectionDelimiter {
+    Comma,
+    Space,
+    Pipe,
+}
+
+impl CollectionDelimiter {
+    fn delimiter(self) -> char {
+        match self {
+            CollectionDelimiter::Comma => ',',
+            CollectionDelimiter::Space => ' ',
+            CollectionDelimiter::Pipe => '|',
+        }
+    }
+
+    fn wrapper_ident(self) -> syn::Ident {
+        match self {
+            CollectionDelimiter::Comma => format_ident!("CommaSeparated"),
+            CollectionDelimiter::Space => format_ident!("SpaceSeparated"),
+            CollectionDelimiter::Pipe => format_ident!("PipeSeparated"),
+        }
+    }
+
+    fn deserialize_fn_ident(self) -> syn::Ident {
+        match self {
+            CollectionDelimiter::Comma => format_ident!("deserialize_comma_separated"),
+            CollectionDelimiter::Space => format_ident!("deserialize_space_separated"),
+            CollectionDelimiter::Pipe => format_ident!("deserialize_pipe_separated"),
+        }
+    }
+}
+
+pub(crate) struct OapiState {
+    definition: OpenAPI,
+    type_cache: BTreeMap<String, syn::Type>,
+    objects: BTreeMap<String, Item>,
     methods: BTreeMap<String, Item>,
+    /// Names of the object schemas currently being emitted, used to detect
+    /// `$ref` cycles while walking `components.schemas`.
+    resolving: Vec<String>,
+    /// For each component schema, the set of other component schemas
+    /// transitively reachable from it through `$ref`s in its properties or
+    /// array items. Used to box a reference whenever it would otherwise
+    /// close a cycle (self- or mutually-recursive) back onto a schema
+    /// currently being emitted.
+    schema_reachable: BTreeMap<String, std::collections::BTreeSet<String>>,
+    /// Axum route path -> ordered `(http_method, handler_ident)` pairs,
+    /// used to emit `router()`.
+    routes: BTreeMap<String, Vec<(String, syn::Ident)>>,
+    /// Collection-format wrapper types referenced by at least one
+    /// parameter, emitted once into `models.rs`.
+    collection_helpers: std::collections::BTreeSet<CollectionDelimiter>,
 }
 
 impl OapiState {
-    fn new() -> Self {
+    pub(crate) fn new(definition: OpenAPI) -> Self {
         OapiState {
-            _type_cache: BTreeMap::default(),
-            _objects: BTreeMap::default(),
+            definition,
+            type_cache: BTreeMap::default(),
+            objects: BTreeMap::default(),
             methods: BTreeMap::default(),
+            resolving: Vec::default(),
+            schema_reachable: BTreeMap::default(),
+            routes: BTreeMap::default(),
+            collection_helpers: std::collections::BTreeSet::default(),
         }
     }
 
-    fn _add_object(&mut self, name: impl AsRef<str>, object: Item) -> anyhow::Result<()> {
-        if self._objects.contains_key(name.as_ref()) {
+    fn require_collection_helper(&mut self, delimiter: CollectionDelimiter) {
+        self.collection_helpers.insert(delimiter);
+    }
+
+    fn add_object(&mut self, name: impl AsRef<str>, object: Item) -> anyhow::Result<()> {
+        if self.objects.contains_key(name.as_ref()) {
             return Err(anyhow::anyhow!("object with this name alredy exist"));
         }
-        self._objects.insert(name.as_ref().to_owned(), object);
+        self.objects.insert(name.as_ref().to_owned(), object);
         Ok(())
     }
 
@@ -35,115 +99,536 @@ impl OapiState {
         self.methods.insert(name.as_ref().to_owned(), method);
         Ok(())
     }
+
+    fn add_route(&mut self, path: impl AsRef<str>, method: impl AsRef<str>, handler: syn::Ident) {
+        self.routes
+            .entry(path.as_ref().to_owned())
+            .or_default()
+            .push((method.as_ref().to_owned(), handler));
+    }
+
+    /// Last path segment of a `#/components/{section}/{name}` pointer.
+    fn component_name(reference: &str) -> &str {
+        reference.rsplit('/').next().unwrap_or(reference)
+    }
+
+    fn resolve_parameter(&self, reference: &str) -> Option<Parameter> {
+        let parameter_or_ref = self
+            .definition
+            .components
+            .as_ref()?
+            .parameters
+            .get(Self::component_name(reference))?;
+        match parameter_or_ref {
+            ReferenceOr::Item(parameter) => Some(parameter.to_owned()),
+            ReferenceOr::Reference { reference } => self.resolve_parameter(reference),
+        }
+    }
+
+    fn resolve_request_body(&self, reference: &str) -> Option<RequestBody> {
+        let request_body_or_ref = self
+            .definition
+            .components
+            .as_ref()?
+            .request_bodies
+            .get(Self::component_name(reference))?;
+        match request_body_or_ref {
+            ReferenceOr::Item(request_body) => Some(request_body.to_owned()),
+            ReferenceOr::Reference { reference } => self.resolve_request_body(reference),
+        }
+    }
+
+    fn resolve_response(&self, reference: &str) -> Option<Response> {
+        let response_or_ref = self
+            .definition
+            .components
+            .as_ref()?
+            .responses
+            .get(Self::component_name(reference))?;
+        match response_or_ref {
+            ReferenceOr::Item(response) => Some(response.to_owned()),
+            ReferenceOr::Reference { reference } => self.resolve_response(reference),
+        }
+    }
+
+    /// Resolves a `#/paths/{json-pointer-escaped-path}` reference.
+    fn resolve_path_item(&self, reference: &str) -> Option<PathItem> {
+        let pointer = reference.strip_prefix("#/paths/")?;
+        let path_name = pointer.replace("~1", "/").replace("~0", "~");
+        self.definition
+            .paths
+            .paths
+            .get(&path_name)?
+            .as_item()
+            .cloned()
+    }
 }
 
-pub(crate) fn generate(oapi_definition: &OpenAPI) -> anyhow::Result<BTreeMap<String, String>> {
+pub(crate) fn generate(state: &mut OapiState) -> anyhow::Result<BTreeMap<String, String>> {
     let mut files: BTreeMap<String, String> = BTreeMap::default();
-    let mut state = OapiState::new();
+    let oapi_definition = state.definition.clone();
+
+    generate_schemas(&oapi_definition, state)?;
 
     for (path_name, path_or_ref) in oapi_definition.paths.paths.iter() {
-        match path_or_ref {
-            openapiv3::ReferenceOr::Reference { reference } => {
-                let _ = reference;
-                unimplemented!()
-            }
-            openapiv3::ReferenceOr::Item(path_item) => {
-                let path_arguments =
-                    generate_path_args(&oapi_definition, &mut state, &path_item.parameters);
-                for (method, operation) in MethodsIterator::new(&path_item) {
-                    generate_operation(
-                        &oapi_definition,
-                        &mut state,
-                        &path_arguments,
-                        method,
-                        path_name,
-                        operation,
-                    );
-                }
-            }
+        let path_item = match path_or_ref {
+            openapiv3::ReferenceOr::Reference { reference } => state
+                .resolve_path_item(reference)
+                .ok_or_else(|| anyhow::anyhow!("unresolved path item reference: {reference}"))?,
+            openapiv3::ReferenceOr::Item(path_item) => path_item.to_owned(),
+        };
+
+        let path_arguments = generate_path_args(state, &path_item.parameters)?;
+        for (method, operation) in MethodsIterator::new(&path_item) {
+            generate_operation(state, &path_arguments, method, path_name, operation)?;
         }
     }
 
-    let file = syn::File {
+    let router_item = generate_router(state);
+    let collection_helpers: Vec<Item> = state
+        .collection_helpers
+        .iter()
+        .flat_map(|delimiter| generate_collection_helper(*delimiter))
+        .collect();
+
+    let models_file = syn::File {
         attrs: vec![],
-        items: state.methods.values().cloned().collect(),
+        items: collection_helpers
+            .into_iter()
+            .chain(state.objects.values().cloned())
+            .collect(),
         shebang: None,
     };
-    let file_content = prettyplease::unparse(&file).replace("type newline = ();", "");
-    files.insert("somefile.rs".to_string(), file_content);
+    files.insert("models.rs".to_string(), render_file(&models_file));
+
+    let handlers_file = syn::File {
+        attrs: vec![],
+        items: vec![
+            syn::parse_quote!(use axum::extract::{Path, Query, State};),
+            syn::parse_quote!(use axum::{Form, Json};),
+            syn::parse_quote!(use super::models::*;),
+            syn::parse_quote!(use crate::ApiState;),
+        ]
+        .into_iter()
+        .chain(state.methods.values().cloned())
+        .collect(),
+        shebang: None,
+    };
+    files.insert("handlers.rs".to_string(), render_file(&handlers_file));
+
+    let router_file = syn::File {
+        attrs: vec![],
+        items: vec![
+            syn::parse_quote!(use super::handlers::*;),
+            syn::parse_quote!(use crate::ApiState;),
+            router_item,
+        ],
+        shebang: None,
+    };
+    files.insert("router.rs".to_string(), render_file(&router_file));
+
+    let mod_file = syn::File {
+        attrs: vec![],
+        items: vec![
+            syn::parse_quote!(pub mod models;),
+            syn::parse_quote!(pub mod handlers;),
+            syn::parse_quote!(pub mod router;),
+        ],
+        shebang: None,
+    };
+    files.insert("mod.rs".to_string(), render_file(&mod_file));
 
     Ok(files)
 }
 
-fn get_parameter_type(parameter: &Parameter) -> Type {
-    let required = parameter.parameter_data_ref().required;
-    let r#type = match &parameter.parameter_data_ref().format {
-        openapiv3::ParameterSchemaOrContent::Schema(schema_or_ref) => match schema_or_ref {
-            ReferenceOr::Reference { reference } => {
-                let _ = reference;
-                unimplemented!()
+/// Renders a generated `syn::File` to source text, stripping the spurious
+/// `type newline = ();` item `prettyplease` inserts for an otherwise-empty
+/// file.
+fn render_file(file: &syn::File) -> String {
+    prettyplease::unparse(file).replace("type newline = ();", "")
+}
+
+/// Direct `$ref` targets (into `#/components/schemas/...`) reachable from a
+/// schema's properties or array items, without following through another
+/// `$ref` boundary.
+fn schema_direct_refs(schema: &Schema, refs: &mut std::collections::BTreeSet<String>) {
+    match &schema.schema_kind {
+        SchemaKind::Type(openapiv3::Type::Object(object)) => {
+            for property in object.properties.values() {
+                schema_ref_direct_refs(property, refs);
+            }
+        }
+        SchemaKind::Type(openapiv3::Type::Array(array)) => {
+            if let Some(items) = &array.items {
+                schema_ref_direct_refs(items, refs);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn schema_ref_direct_refs(
+    schema_or_ref: &ReferenceOr<Box<Schema>>,
+    refs: &mut std::collections::BTreeSet<String>,
+) {
+    match schema_or_ref {
+        ReferenceOr::Reference { reference } => {
+            refs.insert(OapiState::component_name(reference).to_owned());
+        }
+        ReferenceOr::Item(schema) => schema_direct_refs(schema, refs),
+    }
+}
+
+/// For every component schema, the set of other component schemas
+/// transitively reachable from it via `$ref`s, computed by a BFS over the
+/// direct-reference graph. A schema appears in its own reachable set only
+/// if there is an actual cycle leading back to it (self- or mutually
+/// recursive), which is exactly when a reference to it must be boxed.
+fn schema_reachability(
+    components: &openapiv3::Components,
+) -> BTreeMap<String, std::collections::BTreeSet<String>> {
+    let mut direct_refs: BTreeMap<String, std::collections::BTreeSet<String>> = BTreeMap::new();
+    for (schema_name, schema_or_ref) in components.schemas.iter() {
+        if let ReferenceOr::Item(schema) = schema_or_ref {
+            let mut refs = std::collections::BTreeSet::new();
+            schema_direct_refs(schema, &mut refs);
+            direct_refs.insert(schema_name.to_owned(), refs);
+        }
+    }
+
+    direct_refs
+        .keys()
+        .map(|start| {
+            let mut reachable = std::collections::BTreeSet::new();
+            let mut queue = std::collections::VecDeque::new();
+            queue.push_back(start.to_owned());
+            while let Some(current) = queue.pop_front() {
+                if let Some(neighbors) = direct_refs.get(&current) {
+                    for neighbor in neighbors {
+                        if reachable.insert(neighbor.to_owned()) {
+                            queue.push_back(neighbor.to_owned());
+                        }
+                    }
+                }
             }
+            (start.to_owned(), reachable)
+        })
+        .collect()
+}
+
+/// Walks `components.schemas` and emits a model struct for every object
+/// schema, caching the resulting type under its schema name so that later
+/// `$ref`s resolve to the same generated ident.
+fn generate_schemas(oapi_definition: &OpenAPI, state: &mut OapiState) -> anyhow::Result<()> {
+    let Some(components) = oapi_definition.components.as_ref() else {
+        return Ok(());
+    };
+
+    state.schema_reachable = schema_reachability(components);
+
+    // Pre-register every object schema's ident before emitting any struct so
+    // that schemas can reference each other regardless of declaration order.
+    for (schema_name, schema_or_ref) in components.schemas.iter() {
+        if let ReferenceOr::Item(schema) = schema_or_ref {
+            if let SchemaKind::Type(openapiv3::Type::Object(_)) = &schema.schema_kind {
+                let type_ident = format_ident!("{}", schema_name.to_pascal_case());
+                state
+                    .type_cache
+                    .insert(schema_name.to_owned(), syn::parse2(quote!(#type_ident))?);
+            }
+        }
+    }
+
+    for (schema_name, schema_or_ref) in components.schemas.iter() {
+        let ReferenceOr::Item(schema) = schema_or_ref else {
+            continue;
+        };
+        if let SchemaKind::Type(openapiv3::Type::Object(object)) = &schema.schema_kind {
+            generate_object_struct(schema_name, object, state)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Emits `#[derive(Serialize, Deserialize)] pub struct {name} { ... }` for an
+/// object schema and registers it with `state`, recursively hoisting any
+/// inline anonymous object properties into their own named structs.
+fn generate_object_struct(
+    name: impl AsRef<str>,
+    object: &ObjectType,
+    state: &mut OapiState,
+) -> anyhow::Result<()> {
+    let struct_ident = format_ident!("{}", name.as_ref().to_pascal_case());
+
+    state.resolving.push(name.as_ref().to_owned());
+    let fields = (|| -> anyhow::Result<Vec<TokenStream>> {
+        let mut fields = vec![];
+        for (property_name, property_schema) in object.properties.iter() {
+            let field_ident = format_ident!("{}", property_name.to_snake_case());
+            let hoist_name = format!("{}{}", name.as_ref(), property_name.to_pascal_case());
+            let mut field_type = schema_ref_to_type(property_schema, hoist_name, state)?;
+            if !object.required.contains(property_name) {
+                field_type = syn::parse2(quote!(Option<#field_type>))?;
+            }
+
+            let rename = (&field_ident.to_string() != property_name)
+                .then(|| quote!(#[serde(rename = #property_name)]));
+            fields.push(quote! {
+                #rename
+                pub #field_ident: #field_type
+            });
+        }
+        Ok(fields)
+    })();
+    state.resolving.pop();
+    let mut fields = fields?;
+
+    if matches!(
+        object.additional_properties,
+        Some(AdditionalProperties::Any(true))
+    ) {
+        fields.push(quote! {
+            #[serde(flatten)]
+            pub other_fields: std::collections::BTreeMap<String, serde_json::Value>
+        });
+    }
+
+    let tokens = quote! {
+        #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+        pub struct #struct_ident {
+            #( #fields , )*
+        }
+    };
+
+    state.add_object(name.as_ref(), syn::parse2::<Item>(tokens)?)
+}
+
+/// Resolves a (possibly `$ref`'d) schema to the `syn::Type` it should be
+/// represented as, hoisting inline object schemas as it goes.
+fn schema_ref_to_type(
+    schema_or_ref: &ReferenceOr<Box<Schema>>,
+    hoist_name: impl AsRef<str>,
+    state: &mut OapiState,
+) -> anyhow::Result<Type> {
+    match schema_or_ref {
+        ReferenceOr::Reference { reference } => Ok(resolve_schema_type_reference(reference, state)),
+        ReferenceOr::Item(schema) => schema_to_type(schema, hoist_name, state),
+    }
+}
+
+/// Same as `schema_ref_to_type`, but for call sites (request/response
+/// bodies) whose `ReferenceOr<Schema>` isn't boxed the way schema
+/// properties and array items are.
+fn schema_or_ref_to_type(
+    schema_or_ref: &ReferenceOr<Schema>,
+    hoist_name: impl AsRef<str>,
+    state: &mut OapiState,
+) -> anyhow::Result<Type> {
+    match schema_or_ref {
+        ReferenceOr::Reference { reference } => Ok(resolve_schema_type_reference(reference, state)),
+        ReferenceOr::Item(schema) => schema_to_type(schema, hoist_name, state),
+    }
+}
+
+/// Looks up the cached type for a `#/components/schemas/{name}` pointer,
+/// falling back to the bare ident if the target hasn't been cached yet. If
+/// the reference points at a schema that is still being emitted (a `$ref`
+/// cycle), the recursion point is boxed instead of inlined.
+fn resolve_schema_type_reference(reference: impl AsRef<str>, state: &mut OapiState) -> Type {
+    let name = OapiState::component_name(reference.as_ref()).to_owned();
+    let cached = state.type_cache.get(&name).cloned().unwrap_or_else(|| {
+        let ident = format_ident!("{}", name.to_pascal_case());
+        syn::parse2(quote!(#ident)).unwrap()
+    });
+
+    // Box the reference if following it could lead back (directly or
+    // through any number of other schemas) to a schema currently being
+    // emitted, which would otherwise produce an infinite-size struct.
+    let reachable_from_name = state.schema_reachable.get(&name);
+    let closes_a_cycle = state
+        .resolving
+        .iter()
+        .any(|ancestor| reachable_from_name.is_some_and(|reachable| reachable.contains(ancestor)));
+
+    if closes_a_cycle {
+        syn::parse2(quote!(Box<#cached>)).unwrap()
+    } else {
+        cached
+    }
+}
+
+fn schema_to_type(
+    schema: &Schema,
+    hoist_name: impl AsRef<str>,
+    state: &mut OapiState,
+) -> anyhow::Result<Type> {
+    match &schema.schema_kind {
+        SchemaKind::Type(openapiv3::Type::String(_)) => Ok(syn::parse_str("String")?),
+        SchemaKind::Type(openapiv3::Type::Number(_)) => Ok(syn::parse_str("f64")?),
+        SchemaKind::Type(openapiv3::Type::Integer(_)) => Ok(syn::parse_str("i64")?),
+        SchemaKind::Type(openapiv3::Type::Boolean(_)) => Ok(syn::parse_str("bool")?),
+        SchemaKind::Type(openapiv3::Type::Array(array)) => {
+            let item_type = match &array.items {
+                Some(items) => {
+                    schema_ref_to_type(items, format!("{}Item", hoist_name.as_ref()), state)?
+                }
+                None => syn::parse_str("serde_json::Value")?,
+            };
+            Ok(syn::parse2(quote!(Vec<#item_type>))?)
+        }
+        SchemaKind::Type(openapiv3::Type::Object(object)) => {
+            generate_object_struct(hoist_name.as_ref(), object, state)?;
+            let ident = format_ident!("{}", hoist_name.as_ref().to_pascal_case());
+            Ok(syn::parse2(quote!(#ident))?)
+        }
+        // `oneOf`/`allOf`/`anyOf` composition and unconstrained `any` schemas
+        // have no single Rust type to map to; fall back to an untyped JSON
+        // value rather than rejecting an otherwise-valid spec.
+        SchemaKind::OneOf { .. }
+        | SchemaKind::AllOf { .. }
+        | SchemaKind::AnyOf { .. }
+        | SchemaKind::Any(_) => Ok(syn::parse_str("serde_json::Value")?),
+        SchemaKind::Not { .. } => Ok(syn::parse_str("serde_json::Value")?),
+    }
+}
+
+/// Maps a non-array parameter schema to its Rust type. Required/optional
+/// wrapping and array handling are the caller's responsibility (see
+/// `get_parameter_binding`).
+fn get_parameter_type(parameter: &Parameter, state: &mut OapiState) -> Type {
+    match &parameter.parameter_data_ref().format {
+        openapiv3::ParameterSchemaOrContent::Schema(schema_or_ref) => match schema_or_ref {
+            ReferenceOr::Reference { reference } => resolve_schema_type_reference(reference, state),
             ReferenceOr::Item(schema) => match &schema.schema_kind {
                 openapiv3::SchemaKind::Type(schema_type) => match schema_type {
-                    openapiv3::Type::String(_) => "String",
-                    openapiv3::Type::Number(_) => "f64",
-                    openapiv3::Type::Integer(_) => "i64",
+                    openapiv3::Type::String(_) => syn::parse_str("String").unwrap(),
+                    openapiv3::Type::Number(_) => syn::parse_str("f64").unwrap(),
+                    openapiv3::Type::Integer(_) => syn::parse_str("i64").unwrap(),
                     openapiv3::Type::Object(_) => unimplemented!(),
-                    openapiv3::Type::Array(_) => unimplemented!(),
-                    openapiv3::Type::Boolean(_) => "bool",
+                    openapiv3::Type::Array(_) => {
+                        unreachable!("array parameters are handled by get_parameter_binding")
+                    }
+                    openapiv3::Type::Boolean(_) => syn::parse_str("bool").unwrap(),
                 },
                 _ => unimplemented!(),
             },
         },
         openapiv3::ParameterSchemaOrContent::Content(_) => unimplemented!(),
+    }
+}
+
+/// The OpenAPI array schema of a parameter, if its schema is an inline
+/// array (as opposed to a scalar, object, or `$ref`).
+fn parameter_array_items(parameter: &Parameter) -> Option<&openapiv3::ArrayType> {
+    let openapiv3::ParameterSchemaOrContent::Schema(ReferenceOr::Item(schema)) =
+        &parameter.parameter_data_ref().format
+    else {
+        return None;
     };
-    let r#type = if required {
-        r#type.to_owned()
-    } else {
-        format!("Option<{}>", r#type)
+    match &schema.schema_kind {
+        SchemaKind::Type(openapiv3::Type::Array(array)) => Some(array),
+        _ => None,
+    }
+}
+
+/// Which collection format (if any) this array parameter is serialized
+/// with, per the OpenAPI `style`/`explode` combination.
+fn collection_delimiter(parameter: &Parameter) -> Option<CollectionDelimiter> {
+    match parameter {
+        Parameter::Query {
+            parameter_data,
+            style,
+            ..
+        } => {
+            // Per the OpenAPI spec, `form` defaults to `explode: true`.
+            let explode = parameter_data.explode.unwrap_or(true);
+            match style {
+                openapiv3::QueryStyle::Form if explode => None,
+                openapiv3::QueryStyle::Form => Some(CollectionDelimiter::Comma),
+                openapiv3::QueryStyle::SpaceDelimited => Some(CollectionDelimiter::Space),
+                openapiv3::QueryStyle::PipeDelimited => Some(CollectionDelimiter::Pipe),
+                openapiv3::QueryStyle::DeepObject => None,
+            }
+        }
+        Parameter::Path {
+            style: openapiv3::PathStyle::Simple,
+            ..
+        } => Some(CollectionDelimiter::Comma),
+        _ => None,
+    }
+}
+
+/// Computes the argument-binding pattern and extractor type for a single
+/// parameter, resolving array item types and, for collection-format
+/// arrays, wrapping the binding in the matching delimiter helper.
+fn get_parameter_binding(
+    parameter: &Parameter,
+    ident: &syn::Ident,
+    state: &mut OapiState,
+) -> (TokenStream, Type) {
+    let required = parameter.parameter_data_ref().required;
+
+    let (pattern, ty) = match parameter_array_items(parameter) {
+        None => (quote!(#ident), get_parameter_type(parameter, state)),
+        Some(array) => {
+            let item_type = match &array.items {
+                Some(items) => {
+                    schema_ref_to_type(items, format!("{ident}Item"), state).unwrap()
+                }
+                None => syn::parse_str("serde_json::Value").unwrap(),
+            };
+            let vec_type: Type = syn::parse2(quote!(Vec<#item_type>)).unwrap();
+
+            match collection_delimiter(parameter) {
+                None => (quote!(#ident), vec_type),
+                Some(delimiter) => {
+                    state.require_collection_helper(delimiter);
+                    let wrapper_ident = delimiter.wrapper_ident();
+                    (
+                        quote!(#wrapper_ident(#ident)),
+                        syn::parse2(quote!(#wrapper_ident<#item_type>)).unwrap(),
+                    )
+                }
+            }
+        }
     };
-    syn::parse_str::<Type>(&r#type).unwrap()
+
+    if required {
+        (pattern, ty)
+    } else {
+        (pattern, syn::parse2(quote!(Option<#ty>)).unwrap())
+    }
 }
 
 fn generate_path_args(
-    definition: &OpenAPI,
-    _state: &mut OapiState,
+    state: &mut OapiState,
     parameters: &[ReferenceOr<Parameter>],
-) -> Option<FnArg> {
-    let parameters: Vec<_> = parameters
+) -> anyhow::Result<Option<FnArg>> {
+    let parameters: Vec<Parameter> = parameters
         .iter()
         .map(|param_or_ref| match param_or_ref {
-            ReferenceOr::Reference { reference } => {
-                let s: Vec<_> = reference.split("/").collect();
-                let param = definition
-                    .components
-                    .as_ref()
-                    .unwrap()
-                    .parameters
-                    .get(s.last().unwrap().to_owned())
-                    .unwrap()
-                    .as_item()
-                    .unwrap();
-
-                param
-            }
-            ReferenceOr::Item(param) => param,
+            ReferenceOr::Reference { reference } => state
+                .resolve_parameter(reference)
+                .ok_or_else(|| anyhow::anyhow!("unresolved parameter reference: {reference}")),
+            ReferenceOr::Item(param) => Ok(param.to_owned()),
         })
-        .collect();
+        .collect::<anyhow::Result<_>>()?;
 
-    let path_names: Vec<_> = parameters
+    let path_idents: Vec<_> = parameters
         .iter()
         .map(|param| format_ident!("{}", param.parameter_data_ref().name.to_snake_case()))
         .collect();
-    let path_types: Vec<_> = parameters
+    let (path_patterns, path_types): (Vec<_>, Vec<_>) = parameters
         .iter()
-        .map(|param| get_parameter_type(param))
-        .collect();
-    if !path_names.is_empty() {
-        let p = quote!(Path(( #( #path_names , )* )) : Path<( #( #path_types , )* )>);
-        Some(syn::parse2(p).unwrap())
+        .zip(path_idents.iter())
+        .map(|(param, ident)| get_parameter_binding(param, ident, state))
+        .unzip();
+    if !path_patterns.is_empty() {
+        let p = quote!(Path(( #( #path_patterns , )* )) : Path<( #( #path_types , )* )>);
+        Ok(Some(syn::parse2(p)?))
     } else {
-        None
+        Ok(None)
     }
 }
 
@@ -176,11 +661,11 @@ fn generate_operation_docs(
 }
 
 fn generate_operation_args(
-    _definition: &OpenAPI,
-    _state: &mut OapiState,
+    state: &mut OapiState,
     path_arguments: &Option<FnArg>,
+    operation_name: impl AsRef<str>,
     operation: &Operation,
-) -> Vec<FnArg> {
+) -> anyhow::Result<(Vec<FnArg>, TokenStream)> {
     // Add ApiState to every method
     let mut fn_args: Vec<FnArg> =
         vec![syn::parse_str::<FnArg>("State(state): State<ApiState>").expect("this should parse")];
@@ -189,90 +674,492 @@ fn generate_operation_args(
         fn_args.push(arg.to_owned());
     }
 
-    let mut arg_idents: Vec<Expr> = vec![];
+    let mut arg_patterns: Vec<TokenStream> = vec![];
     let mut arg_types: Vec<Type> = vec![];
     for parameter_or_ref in operation.parameters.iter() {
-        match parameter_or_ref {
-            ReferenceOr::Reference { reference } => {
-                let _ = reference;
-                unimplemented!()
-            }
-            ReferenceOr::Item(parameter) => {
-                let arg_name = format!("{}", parameter.parameter_data_ref().name.to_snake_case());
-                let ident = syn::parse_str::<Expr>(&arg_name).unwrap();
-                let ty = get_parameter_type(parameter);
-                arg_idents.push(ident);
-                arg_types.push(ty);
-            }
+        let parameter = match parameter_or_ref {
+            ReferenceOr::Reference { reference } => state
+                .resolve_parameter(reference)
+                .ok_or_else(|| anyhow::anyhow!("unresolved parameter reference: {reference}"))?,
+            ReferenceOr::Item(parameter) => parameter.to_owned(),
         };
+        let arg_name = parameter.parameter_data_ref().name.to_snake_case();
+        let ident = format_ident!("{}", arg_name);
+        let (pattern, ty) = get_parameter_binding(&parameter, &ident, state);
+        arg_patterns.push(pattern);
+        arg_types.push(ty);
     }
 
-    for (ident, ty) in arg_idents.iter().zip(arg_types.iter()) {
-        let arg = quote!(Query( #ident ) : Query<#ty>);
+    for (pattern, ty) in arg_patterns.iter().zip(arg_types.iter()) {
+        let arg = quote!(Query( #pattern ) : Query<#ty>);
         fn_args.push(syn::parse2::<FnArg>(arg).unwrap());
     }
 
-    let request_arg = operation
-        .request_body
-        .as_ref()
-        .map(|request_or_ref| match request_or_ref {
-            ReferenceOr::Reference { reference } => {
-                let _ = reference;
-                unimplemented!();
-            }
-            ReferenceOr::Item(request_body) => {
-                request_body
-                    .content
-                    .first()
-                    .map(|(media_type, _value)| match media_type.as_str() {
-                        "application/json" => {
-                            let request_arg = format!("Json(request): Json<TODO>");
-                            syn::parse_str::<FnArg>(&request_arg).unwrap()
-                        }
-                        "application/x-www-form-urlencoded" => {
-                            let request_arg = format!("Form(request): Form<TODO>");
-                            syn::parse_str::<FnArg>(&request_arg).unwrap()
-                        }
-                        _ => unimplemented!(),
-                    })
-            }
-        })
-        .flatten();
+    let mut preamble = TokenStream::new();
+
+    let request_arg = match operation.request_body.as_ref() {
+        Some(request_or_ref) => {
+            let request_body = match request_or_ref {
+                ReferenceOr::Reference { reference } => state
+                    .resolve_request_body(reference)
+                    .ok_or_else(|| anyhow::anyhow!("unresolved request body reference: {reference}"))?,
+                ReferenceOr::Item(request_body) => request_body.to_owned(),
+            };
+            request_body
+                .content
+                .first()
+                .map(|(media_type, media)| match media_type.as_str() {
+                    "application/json" => {
+                        let body_type =
+                            request_body_type(media, format!("{}Request", operation_name.as_ref().to_pascal_case()), state);
+                        Ok(syn::parse2::<FnArg>(quote!(Json(request): Json<#body_type>)).unwrap())
+                    }
+                    "application/x-www-form-urlencoded" => {
+                        let body_type =
+                            request_body_type(media, format!("{}Request", operation_name.as_ref().to_pascal_case()), state);
+                        Ok(syn::parse2::<FnArg>(quote!(Form(request): Form<#body_type>)).unwrap())
+                    }
+                    "multipart/form-data" => {
+                        let hoist_name = format!("{}Upload", operation_name.as_ref().to_pascal_case());
+                        let (arg, field_preamble) =
+                            generate_multipart_arg(media, hoist_name, state);
+                        preamble = field_preamble;
+                        Ok(arg)
+                    }
+                    _ => unimplemented!(),
+                })
+                .transpose()?
+        }
+        None => None,
+    };
 
     if let Some(arg) = request_arg {
         fn_args.push(arg);
     }
 
-    fn_args
+    Ok((fn_args, preamble))
 }
 
-fn generate_operation_response() -> Option<ReturnType> {
-    Some(syn::parse_str("-> Result<TODO, TODO>").unwrap())
+/// Resolves a JSON/form request body's declared schema to its Rust type,
+/// falling back to `serde_json::Value` when no schema is declared.
+fn request_body_type(
+    media: &openapiv3::MediaType,
+    hoist_name: impl AsRef<str>,
+    state: &mut OapiState,
+) -> Type {
+    media
+        .schema
+        .as_ref()
+        .map(|schema| {
+            schema_or_ref_to_type(schema, hoist_name, state)
+                .expect("request body schema should resolve")
+        })
+        .unwrap_or_else(|| syn::parse_str("serde_json::Value").unwrap())
+}
+
+/// Builds the `Multipart` extractor argument and field-extraction preamble
+/// for a `multipart/form-data` request body. Properties with
+/// `format: binary` map to raw `axum::body::Bytes`; every other property is
+/// mapped through the usual scalar type mapper and parsed from its text
+/// value. Modeled on paperclip's treatment of binary-format fields as
+/// streamed file content rather than ordinary strings.
+fn generate_multipart_arg(
+    media: &openapiv3::MediaType,
+    hoist_name: impl AsRef<str>,
+    state: &mut OapiState,
+) -> (FnArg, TokenStream) {
+    let schema = media
+        .schema
+        .as_ref()
+        .expect("multipart/form-data body should declare a schema");
+    let ReferenceOr::Item(schema) = schema else {
+        unimplemented!("multipart/form-data body schema must be inline, not a $ref");
+    };
+    let SchemaKind::Type(openapiv3::Type::Object(object)) = &schema.schema_kind else {
+        unimplemented!("multipart/form-data body schema must be an object");
+    };
+
+    let mut declarations: Vec<TokenStream> = vec![];
+    let mut arms: Vec<TokenStream> = vec![];
+    let mut finalizers: Vec<Option<TokenStream>> = vec![];
+
+    for (property_name, property_schema) in object.properties.iter() {
+        let field_ident = format_ident!("{}", property_name.to_snake_case());
+        let required = object.required.contains(property_name);
+
+        let is_binary = matches!(
+            property_schema,
+            ReferenceOr::Item(schema)
+                if matches!(
+                    &schema.schema_kind,
+                    SchemaKind::Type(openapiv3::Type::String(string_type))
+                        if string_type.format
+                            == openapiv3::VariantOrUnknownOrEmpty::Item(openapiv3::StringFormat::Binary)
+                )
+        );
+
+        if is_binary {
+            declarations.push(quote! {
+                let mut #field_ident: Option<axum::body::Bytes> = None;
+            });
+            arms.push(quote! {
+                Some(#property_name) => #field_ident = Some(field.bytes().await.unwrap()),
+            });
+        } else {
+            let field_hoist_name =
+                format!("{}{}", hoist_name.as_ref(), property_name.to_pascal_case());
+            let field_type = schema_ref_to_type(property_schema, field_hoist_name, state)
+                .expect("multipart field schema should resolve");
+            declarations.push(quote! {
+                let mut #field_ident: Option<#field_type> = None;
+            });
+            arms.push(quote! {
+                Some(#property_name) => {
+                    #field_ident = Some(field.text().await.unwrap().parse::<#field_type>().unwrap())
+                }
+            });
+        }
+
+        if required {
+            finalizers.push(Some(quote! {
+                let #field_ident = #field_ident.expect(concat!("missing multipart field: ", #property_name));
+            }));
+        } else {
+            finalizers.push(None);
+        }
+    }
+
+    let preamble = quote! {
+        #( #declarations )*
+        while let Some(field) = multipart.next_field().await.unwrap() {
+            let field_name = field.name().map(str::to_owned);
+            match field_name.as_deref() {
+                #( #arms )*
+                _ => {}
+            }
+        }
+        #( #finalizers )*
+    };
+
+    let arg = syn::parse_str::<FnArg>("mut multipart: axum::extract::Multipart").unwrap();
+    (arg, preamble)
+}
+
+/// Maps a numeric HTTP status code to the PascalCase identifier used for its
+/// response-enum variant (e.g. `200` -> `Ok`, `404` -> `NotFound`), falling
+/// back to `Status{code}` for codes without a well-known name.
+fn status_code_variant_ident(code: u16) -> syn::Ident {
+    let name = match code {
+        200 => "Ok",
+        201 => "Created",
+        202 => "Accepted",
+        204 => "NoContent",
+        301 => "MovedPermanently",
+        302 => "Found",
+        304 => "NotModified",
+        400 => "BadRequest",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "NotFound",
+        405 => "MethodNotAllowed",
+        409 => "Conflict",
+        410 => "Gone",
+        422 => "UnprocessableEntity",
+        429 => "TooManyRequests",
+        500 => "InternalServerError",
+        502 => "BadGateway",
+        503 => "ServiceUnavailable",
+        _ => return format_ident!("Status{}", code),
+    };
+    format_ident!("{}", name)
+}
+
+/// How a response-enum variant carries its body, if any.
+enum ResponseBody {
+    /// `application/json`, serialized via `axum::Json`.
+    Json(Type),
+    /// Any other media type, passed through as raw bytes.
+    Bytes,
+}
+
+/// The response body a response's first media type entry should be
+/// represented as. `application/json` schemas are resolved through the type
+/// mapper; any other media type (`text/plain`, `application/octet-stream`,
+/// etc.) is carried as raw bytes rather than rejected.
+fn response_body_type(
+    response: &Response,
+    hoist_name: impl AsRef<str>,
+    state: &mut OapiState,
+) -> Option<ResponseBody> {
+    let (media_type, media) = response.content.first()?;
+    match media_type.as_str() {
+        "application/json" => {
+            let schema = media.schema.as_ref()?;
+            Some(ResponseBody::Json(
+                schema_or_ref_to_type(schema, hoist_name, state)
+                    .expect("response schema should resolve"),
+            ))
+        }
+        _ => Some(ResponseBody::Bytes),
+    }
+}
+
+/// Builds a per-operation response enum (`{Operation}Response`) with one
+/// variant per documented status code, plus an `IntoResponse` impl that maps
+/// each variant back to its status code and (optional) body: JSON for
+/// `application/json`, raw bytes for any other media type. Both are
+/// registered on `state` alongside the model structs.
+fn generate_operation_response(
+    state: &mut OapiState,
+    operation_name: impl AsRef<str>,
+    operation: &Operation,
+) -> anyhow::Result<Option<ReturnType>> {
+    let enum_name = format!("{}Response", operation_name.as_ref().to_pascal_case());
+    let enum_ident = format_ident!("{}", enum_name);
+
+    let mut variant_defs: Vec<TokenStream> = vec![];
+    let mut match_arms: Vec<TokenStream> = vec![];
+
+    for (status_code, response_or_ref) in operation.responses.responses.iter() {
+        let response = match response_or_ref {
+            ReferenceOr::Reference { reference } => state
+                .resolve_response(reference)
+                .ok_or_else(|| anyhow::anyhow!("unresolved response reference: {reference}"))?,
+            ReferenceOr::Item(response) => response.to_owned(),
+        };
+        let code: u16 = match status_code {
+            openapiv3::StatusCode::Code(code) => *code,
+            openapiv3::StatusCode::Range(range) => *range as u16 * 100,
+        };
+        let variant_ident = status_code_variant_ident(code);
+        let hoist_name = format!("{enum_name}{variant_ident}");
+        let doc = (!response.description.is_empty())
+            .then(|| response.description.clone())
+            .map(|doc| quote!(#[doc = #doc]));
+
+        match response_body_type(&response, hoist_name, state) {
+            Some(ResponseBody::Json(body_type)) => {
+                variant_defs.push(quote! {
+                    #doc
+                    #variant_ident(#body_type)
+                });
+                match_arms.push(quote! {
+                    #enum_ident::#variant_ident(body) => {
+                        (axum::http::StatusCode::from_u16(#code).unwrap(), axum::Json(body)).into_response()
+                    }
+                });
+            }
+            Some(ResponseBody::Bytes) => {
+                variant_defs.push(quote! {
+                    #doc
+                    #variant_ident(axum::body::Bytes)
+                });
+                match_arms.push(quote! {
+                    #enum_ident::#variant_ident(body) => {
+                        (axum::http::StatusCode::from_u16(#code).unwrap(), body).into_response()
+                    }
+                });
+            }
+            None => {
+                variant_defs.push(quote! {
+                    #doc
+                    #variant_ident
+                });
+                match_arms.push(quote! {
+                    #enum_ident::#variant_ident => {
+                        axum::http::StatusCode::from_u16(#code).unwrap().into_response()
+                    }
+                });
+            }
+        }
+    }
+
+    if let Some(default_or_ref) = operation.responses.default.as_ref() {
+        let response = match default_or_ref {
+            ReferenceOr::Reference { reference } => state
+                .resolve_response(reference)
+                .ok_or_else(|| anyhow::anyhow!("unresolved response reference: {reference}"))?,
+            ReferenceOr::Item(response) => response.to_owned(),
+        };
+        let hoist_name = format!("{enum_name}Default");
+        let doc = (!response.description.is_empty())
+            .then(|| response.description.clone())
+            .map(|doc| quote!(#[doc = #doc]));
+
+        match response_body_type(&response, hoist_name, state) {
+            Some(ResponseBody::Json(body_type)) => {
+                variant_defs.push(quote! {
+                    #doc
+                    Default(axum::http::StatusCode, #body_type)
+                });
+                match_arms.push(quote! {
+                    #enum_ident::Default(status, body) => (status, axum::Json(body)).into_response()
+                });
+            }
+            Some(ResponseBody::Bytes) => {
+                variant_defs.push(quote! {
+                    #doc
+                    Default(axum::http::StatusCode, axum::body::Bytes)
+                });
+                match_arms.push(quote! {
+                    #enum_ident::Default(status, body) => (status, body).into_response()
+                });
+            }
+            None => {
+                variant_defs.push(quote! {
+                    #doc
+                    Default(axum::http::StatusCode)
+                });
+                match_arms.push(quote! {
+                    #enum_ident::Default(status) => status.into_response()
+                });
+            }
+        }
+    }
+
+    let enum_tokens = quote! {
+        #[derive(Debug, Clone)]
+        pub enum #enum_ident {
+            #( #variant_defs , )*
+        }
+    };
+    let impl_tokens = quote! {
+        impl axum::response::IntoResponse for #enum_ident {
+            fn into_response(self) -> axum::response::Response {
+                match self {
+                    #( #match_arms , )*
+                }
+            }
+        }
+    };
+
+    state
+        .add_object(&enum_name, syn::parse2::<Item>(enum_tokens).unwrap())
+        .expect("operation_name should be unique");
+    state
+        .add_object(
+            format!("{enum_name}IntoResponse"),
+            syn::parse2::<Item>(impl_tokens).unwrap(),
+        )
+        .expect("operation_name should be unique");
+
+    Ok(Some(syn::parse2(quote!(-> #enum_ident)).unwrap()))
 }
 
 fn generate_operation(
-    definition: &OpenAPI,
     state: &mut OapiState,
     path_arguments: &Option<FnArg>,
     method: impl AsRef<str>,
     path: impl AsRef<str>,
     operation: &Operation,
-) {
+) -> anyhow::Result<()> {
+    let http_method = method.as_ref().to_owned();
+    let axum_path = rewrite_path_template(path.as_ref());
+
     let operation_docs = generate_operation_docs(method, path, operation);
     let operation_name = operation.operation_id.as_ref().unwrap().to_snake_case();
     let operation_ident = format_ident!("{}", operation_name);
-    let operation_args = generate_operation_args(definition, state, path_arguments, operation);
-    let operation_response = generate_operation_response();
+    let (operation_args, request_preamble) =
+        generate_operation_args(state, path_arguments, &operation_name, operation)?;
+    let operation_response = generate_operation_response(state, &operation_name, operation)?;
 
     let tokens = quote! {
         #( #[doc = #operation_docs] )*
         pub async fn #operation_ident ( #( #operation_args ,)* ) #operation_response {
+            #request_preamble
             todo!();
         }
     };
     state
         .add_method(operation_name, syn::parse2::<Item>(tokens).unwrap())
         .expect("operation_name should be unique");
+    state.add_route(axum_path, http_method, operation_ident);
+    Ok(())
+}
+
+/// Rewrites an OpenAPI path template (`/users/{userId}`) into axum's
+/// capture syntax, snake-casing capture names to match the argument names
+/// `generate_path_args` binds.
+fn rewrite_path_template(path: &str) -> String {
+    let mut rewritten = String::with_capacity(path.len());
+    let mut rest = path;
+    while let Some(start) = rest.find('{') {
+        rewritten.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            rewritten.push_str(&rest[start..]);
+            return rewritten;
+        };
+        let name = &rest[start + 1..start + end];
+        rewritten.push('{');
+        rewritten.push_str(&name.to_snake_case());
+        rewritten.push('}');
+        rest = &rest[start + end + 1..];
+    }
+    rewritten.push_str(rest);
+    rewritten
+}
+
+/// Emits the newtype wrapper and `deserialize_with` helper used to parse a
+/// delimited collection-format parameter (e.g. `?tag=a,b,c`) into a `Vec<T>`.
+fn generate_collection_helper(delimiter: CollectionDelimiter) -> [Item; 2] {
+    let wrapper_ident = delimiter.wrapper_ident();
+    let deserialize_fn_ident = delimiter.deserialize_fn_ident();
+    let deserialize_fn_name = deserialize_fn_ident.to_string();
+    let delimiter_char = delimiter.delimiter();
+
+    let wrapper = quote! {
+        #[derive(Debug, Clone, serde::Deserialize)]
+        #[serde(bound = "T: std::str::FromStr, T::Err: std::fmt::Display")]
+        pub struct #wrapper_ident<T>(
+            #[serde(deserialize_with = #deserialize_fn_name)]
+            pub Vec<T>,
+        );
+    };
+
+    let deserialize_fn = quote! {
+        fn #deserialize_fn_ident<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+            T: std::str::FromStr,
+            T::Err: std::fmt::Display,
+        {
+            let raw = <String as serde::Deserialize>::deserialize(deserializer)?;
+            raw.split(#delimiter_char)
+                .map(|item| item.parse::<T>().map_err(serde::de::Error::custom))
+                .collect()
+        }
+    };
+
+    [
+        syn::parse2::<Item>(wrapper).expect("generated collection wrapper should parse"),
+        syn::parse2::<Item>(deserialize_fn).expect("generated collection helper fn should parse"),
+    ]
+}
+
+/// Emits `pub fn router() -> axum::Router<ApiState>`, grouping every method
+/// registered on the same path into a single `.route` call.
+fn generate_router(state: &OapiState) -> Item {
+    let route_calls = state.routes.iter().map(|(path, methods)| {
+        let method_router = methods
+            .iter()
+            .fold(None, |acc, (http_method, handler_ident)| {
+                let method_ident = format_ident!("{}", http_method);
+                Some(match acc {
+                    None => quote!(axum::routing::#method_ident(#handler_ident)),
+                    Some(existing) => quote!(#existing.#method_ident(#handler_ident)),
+                })
+            })
+            .expect("a registered route always has at least one method");
+        quote!(.route(#path, #method_router))
+    });
+
+    let tokens = quote! {
+        pub fn router() -> axum::Router<ApiState> {
+            axum::Router::new()
+                #( #route_calls )*
+        }
+    };
+    syn::parse2::<Item>(tokens).expect("generated router should parse")
 }
 
 struct MethodsIterator<'a> {
@@ -291,7 +1178,7 @@ impl<'a> Iterator for MethodsIterator<'a> {
 
     fn next(&mut self) -> Option<Self::Item> {
         loop {
-            if self.step > 6 {
+            if self.step > 7 {
                 return None;
             }
             let m = match self.step {
@@ -306,22 +1193,23 @@ impl<'a> Iterator for MethodsIterator<'a> {
                     .as_ref()
                     .map(|op| ("head".to_owned(), op)),
                 2 => self.path_item.get.as_ref().map(|op| ("get".to_owned(), op)),
-                3 => self
+                3 => self.path_item.put.as_ref().map(|op| ("put".to_owned(), op)),
+                4 => self
                     .path_item
                     .post
                     .as_ref()
                     .map(|op| ("post".to_owned(), op)),
-                4 => self
+                5 => self
                     .path_item
                     .delete
                     .as_ref()
                     .map(|op| ("delete".to_owned(), op)),
-                5 => self
+                6 => self
                     .path_item
                     .patch
                     .as_ref()
                     .map(|op| ("patch".to_owned(), op)),
-                6 => self
+                7 => self
                     .path_item
                     .trace
                     .as_ref()
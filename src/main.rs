@@ -22,8 +22,12 @@ fn main() -> anyhow::Result<()> {
     let mut state = generator::OapiState::new(schema);
 
     let files = generator::generate(&mut state)?;
-    for (_file, content) in files {
-        println!("{}", content);
+    for (file_name, content) in files {
+        let path = cli.out_dir.join(file_name);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, content)?;
     }
 
     Ok(())